@@ -16,7 +16,7 @@
 //! 		InitCell::init(&MY_VAL, vec![1, 2, 3]);
 //! 	}
 //! 	assert_eq!(MY_VAL.iter().sum::<i32>(), 6);
-//! 
+//!
 //! 	// The cell can be mutated, too, which drops the previous value.
 //! 	unsafe {
 //! 		InitCell::set(&MY_VAL, vec![4, 5, 6]);
@@ -29,18 +29,25 @@
 
 use core::fmt;
 use core::ops::{Deref, DerefMut};
-use core::cell::UnsafeCell;
+use core::cell::{Cell, UnsafeCell};
 use core::mem::MaybeUninit;
 use core::cmp::Ordering;
 
 /// A one-time initialization cell.
-/// 
+///
 /// This is mostly intended for use in statics. The cell is safe to access,
 /// but must be initialized before any access. There is no synchronization
 /// to ensure initialization is observed, so you should initialize at the
 /// beginning of the main function or using something like the `ctor` crate.
-#[repr(transparent)]
-pub struct InitCell<T>(UnsafeCell<MaybeUninit<T>>);
+///
+/// `InitCell` tracks whether it has been initialized, so besides the
+/// unchecked `Deref`/`DerefMut` there is a safe [`InitCell::get`] /
+/// [`InitCell::get_mut`] pair for callers who would rather get `None` than
+/// risk undefined behavior.
+pub struct InitCell<T> {
+	initialized: Cell<bool>,
+	value: UnsafeCell<MaybeUninit<T>>,
+}
 
 unsafe impl<T: Send> Send for InitCell<T> {}
 unsafe impl<T: Sync> Sync for InitCell<T> {}
@@ -85,46 +92,331 @@ impl<T> Deref for InitCell<T> {
 	type Target = T;
 
 	fn deref(&self) -> &T {
-		unsafe { (*self.0.get()).assume_init_ref() }
+		unsafe { (*self.value.get()).assume_init_ref() }
 	}
 }
 
 impl<T> DerefMut for InitCell<T> {
 	fn deref_mut(&mut self) -> &mut T {
-		unsafe { (*self.0.get()).assume_init_mut() }
+		unsafe { (*self.value.get()).assume_init_mut() }
 	}
 }
 
 impl<T> InitCell<T> {
 	/// Creates a new uninitialized `InitCell`.
-	/// 
+	///
 	/// # Safety
 	/// The cell must be initialized before it is accessed.
-	pub const unsafe fn new() -> Self { Self(UnsafeCell::new(MaybeUninit::uninit())) }
-	
+	pub const unsafe fn new() -> Self {
+		Self { initialized: Cell::new(false), value: UnsafeCell::new(MaybeUninit::uninit()) }
+	}
+
 	/// Creates a new initialized `InitCell`. Unlike `InitCell::new`, this is
 	/// safe because the cell is already initialized and can be used freely.
-	pub fn initialized(x: T) -> Self { Self(UnsafeCell::new(MaybeUninit::new(x))) }
+	pub fn initialized(x: T) -> Self {
+		Self { initialized: Cell::new(true), value: UnsafeCell::new(MaybeUninit::new(x)) }
+	}
 
 	/// Gets the inner (initialized) value of this cell.
-	pub unsafe fn into_inner(cell: Self) -> T { cell.0.into_inner().assume_init() }
+	pub unsafe fn into_inner(cell: Self) -> T {
+		let x = (*cell.value.get()).assume_init_read();
+		core::mem::forget(cell);
+		x
+	}
 
 	/// Initializes the cell.
-	/// 
+	///
 	/// # Safety
 	/// This must be done when there are no references to the contents of this
 	/// cell, including no other threads accessing it.
 	pub unsafe fn init(cell: &Self, x: T) {
-		(*cell.0.get()).write(x);
+		(*cell.value.get()).write(x);
+		cell.initialized.set(true);
+	}
+
+	/// Initializes the cell, unless it has already been initialized.
+	///
+	/// Unlike [`InitCell::init`], this refuses to overwrite an
+	/// already-initialized cell, handing `x` back on failure instead of
+	/// assuming the cell is empty, mirroring `once_cell`'s `OnceCell::set`.
+	///
+	/// # Safety
+	/// The initialization flag checked here is a plain `Cell<bool>`, not an
+	/// atomic, so this carries the same contract as [`InitCell::init`]: it
+	/// must be done when there are no references to the contents of this
+	/// cell, including no other threads concurrently calling `try_init`,
+	/// `init`, `set`, or `take` on it. For a version that's safe to call
+	/// under concurrent access, use `OnceInitCell` (behind the
+	/// `critical-section` feature).
+	///
+	/// # Example
+	///
+	/// ```
+	/// use init_cell::InitCell;
+	///
+	/// // SAFETY: Nothing else is accessing the cell.
+	/// let cell = unsafe { InitCell::new() };
+	/// unsafe {
+	/// 	assert_eq!(InitCell::try_init(&cell, 1), Ok(()));
+	/// 	assert_eq!(InitCell::try_init(&cell, 2), Err(2));
+	/// }
+	/// assert_eq!(*cell, 1);
+	/// ```
+	pub unsafe fn try_init(cell: &Self, x: T) -> Result<(), T> {
+		if cell.initialized.get() {
+			return Err(x);
+		}
+
+		(*cell.value.get()).write(x);
+		cell.initialized.set(true);
+		Ok(())
 	}
 
-	/// Sets the cell's value.
-	/// 
+	/// Sets the cell's value, dropping the previous one if the cell was
+	/// already initialized.
+	///
 	/// # Safety
 	/// This must be done when there are no references to the contents of this
-	/// cell, including no other threads accessing it. Additionally, the cell
-	/// must have been previously initialized, as this will drop the old value.
+	/// cell, including no other threads accessing it.
 	pub unsafe fn set(cell: &Self, x: T) {
-		*(*cell.0.get()).as_mut_ptr() = x;
+		if cell.initialized.get() {
+			(*cell.value.get()).assume_init_drop();
+		}
+		(*cell.value.get()).write(x);
+		cell.initialized.set(true);
+	}
+
+	/// Gets the value of this cell, or `None` if it hasn't been initialized.
+	///
+	/// This is the safe, checked counterpart to `Deref`.
+	pub fn get(&self) -> Option<&T> {
+		if self.initialized.get() {
+			Some(unsafe { (*self.value.get()).assume_init_ref() })
+		} else {
+			None
+		}
+	}
+
+	/// Gets the value of this cell mutably, or `None` if it hasn't been
+	/// initialized.
+	///
+	/// This is the safe, checked counterpart to `DerefMut`.
+	pub fn get_mut(&mut self) -> Option<&mut T> {
+		if *self.initialized.get_mut() {
+			Some(unsafe { (*self.value.get()).assume_init_mut() })
+		} else {
+			None
+		}
+	}
+
+	/// Takes the value out of the cell, leaving it uninitialized, and
+	/// returns it, or `None` if the cell wasn't initialized.
+	///
+	/// Unlike [`InitCell::into_inner`], this doesn't consume the cell, so it
+	/// can be used to reclaim and drop a `'static` cell's value, or to reset
+	/// it for re-initialization via [`InitCell::init`] or
+	/// [`InitCell::try_init`].
+	///
+	/// # Example
+	///
+	/// ```
+	/// use init_cell::InitCell;
+	///
+	/// let mut cell = InitCell::initialized(vec![1, 2, 3]);
+	/// assert_eq!(InitCell::take(&mut cell), Some(vec![1, 2, 3]));
+	/// assert_eq!(InitCell::take(&mut cell), None);
+	/// ```
+	pub fn take(cell: &mut Self) -> Option<T> {
+		if cell.initialized.replace(false) {
+			Some(unsafe { (*cell.value.get()).assume_init_read() })
+		} else {
+			None
+		}
 	}
 }
+
+impl<T> Drop for InitCell<T> {
+	fn drop(&mut self) {
+		if *self.initialized.get_mut() {
+			unsafe { (*self.value.get()).assume_init_drop(); }
+		}
+	}
+}
+
+#[cfg(feature = "critical-section")]
+use core::sync::atomic::{AtomicU8, Ordering as AtomicOrdering};
+
+#[cfg(feature = "critical-section")]
+const UNINIT: u8 = 0;
+#[cfg(feature = "critical-section")]
+const INITIALIZING: u8 = 1;
+#[cfg(feature = "critical-section")]
+const INIT: u8 = 2;
+
+/// A synchronized one-time initialization cell.
+///
+/// Unlike [`InitCell`], this cell may be initialized from any thread at any
+/// time: the actual write is guarded by a [`critical_section::with`], and an
+/// `AtomicU8` tracks the state so other threads correctly observe whether
+/// initialization has happened. This gives `once_cell::sync::OnceCell`-style
+/// ergonomics on bare-metal targets that provide a `critical-section`
+/// implementation, while keeping the crate `no_std`.
+///
+/// # Example
+///
+/// ```
+/// use init_cell::OnceInitCell;
+///
+/// static VALUE: OnceInitCell<u32> = OnceInitCell::new();
+///
+/// assert_eq!(*VALUE.get_or_init(|| 42), 42);
+/// assert_eq!(*VALUE.get_or_init(|| 0), 42);
+/// ```
+#[cfg(feature = "critical-section")]
+pub struct OnceInitCell<T> {
+	state: AtomicU8,
+	value: UnsafeCell<MaybeUninit<T>>,
+}
+
+#[cfg(feature = "critical-section")]
+unsafe impl<T: Send> Send for OnceInitCell<T> {}
+#[cfg(feature = "critical-section")]
+unsafe impl<T: Send + Sync> Sync for OnceInitCell<T> {}
+
+#[cfg(feature = "critical-section")]
+impl<T> Default for OnceInitCell<T> {
+	fn default() -> Self { Self::new() }
+}
+
+#[cfg(feature = "critical-section")]
+impl<T> OnceInitCell<T> {
+	/// Creates a new, uninitialized `OnceInitCell`.
+	pub const fn new() -> Self {
+		Self { state: AtomicU8::new(UNINIT), value: UnsafeCell::new(MaybeUninit::uninit()) }
+	}
+
+	/// Gets the value, initializing it with `f` if this cell hasn't been
+	/// initialized yet.
+	///
+	/// If multiple threads call this concurrently before initialization,
+	/// exactly one `f` runs; the others block on the critical section and
+	/// then observe its result.
+	pub fn get_or_init(&self, f: impl FnOnce() -> T) -> &T {
+		if self.state.load(AtomicOrdering::Acquire) != INIT {
+			critical_section::with(|_| {
+				if self.state.load(AtomicOrdering::Acquire) != INIT {
+					self.state.store(INITIALIZING, AtomicOrdering::Relaxed);
+					unsafe { (*self.value.get()).write(f()); }
+					self.state.store(INIT, AtomicOrdering::Release);
+				}
+			});
+		}
+
+		unsafe { (*self.value.get()).assume_init_ref() }
+	}
+
+	/// Gets the value, if this cell has already been initialized.
+	pub fn get(&self) -> Option<&T> {
+		if self.state.load(AtomicOrdering::Acquire) == INIT {
+			Some(unsafe { (*self.value.get()).assume_init_ref() })
+		} else {
+			None
+		}
+	}
+
+	/// Sets the cell's value, returning it back as an error if the cell was
+	/// already initialized.
+	pub fn set(&self, x: T) -> Result<(), T> {
+		if self.get().is_some() {
+			return Err(x);
+		}
+
+		let mut x = Some(x);
+		critical_section::with(|_| {
+			if self.state.load(AtomicOrdering::Acquire) != INIT {
+				self.state.store(INITIALIZING, AtomicOrdering::Relaxed);
+				unsafe { (*self.value.get()).write(x.take().unwrap()); }
+				self.state.store(INIT, AtomicOrdering::Release);
+			}
+		});
+
+		match x {
+			Some(x) => Err(x),
+			None => Ok(()),
+		}
+	}
+}
+
+#[cfg(feature = "critical-section")]
+impl<T> Drop for OnceInitCell<T> {
+	fn drop(&mut self) {
+		if *self.state.get_mut() == INIT {
+			unsafe { (*self.value.get()).assume_init_drop(); }
+		}
+	}
+}
+
+/// A cell that lazily initializes its value with a closure on first access.
+///
+/// This is the synchronized analogue of `once_cell`'s `Lazy`/`lazy_static!`,
+/// built directly on top of [`OnceInitCell`]: the closure `F` runs the first
+/// time the cell is dereferenced (inside `OnceInitCell::get_or_init`), and
+/// the result is cached for subsequent accesses. `LazyInitCell::new` is a
+/// `const fn`, so this can be used directly in `static`s.
+///
+/// Like `OnceInitCell`, this requires the `critical-section` feature: a
+/// `static` must be `Sync`, and getting that right needs real synchronization
+/// rather than `InitCell`'s documented-but-unenforced single-threaded-init
+/// contract. There is currently no unsynchronized, always-available
+/// `LazyInitCell`.
+///
+/// # Example
+///
+/// ```
+/// use init_cell::LazyInitCell;
+///
+/// static VALUE: LazyInitCell<u32> = LazyInitCell::new(|| 42);
+///
+/// assert_eq!(*VALUE, 42);
+/// ```
+#[cfg(feature = "critical-section")]
+pub struct LazyInitCell<T, F = fn() -> T> {
+	cell: OnceInitCell<T>,
+	init: UnsafeCell<Option<F>>,
+}
+
+#[cfg(feature = "critical-section")]
+unsafe impl<T: Send, F: Send> Send for LazyInitCell<T, F> {}
+#[cfg(feature = "critical-section")]
+unsafe impl<T: Send + Sync, F: Send> Sync for LazyInitCell<T, F> {}
+
+#[cfg(feature = "critical-section")]
+impl<T, F> LazyInitCell<T, F> {
+	/// Creates a new `LazyInitCell` that will initialize itself by calling
+	/// `f` on first access.
+	pub const fn new(f: F) -> Self {
+		Self { cell: OnceInitCell::new(), init: UnsafeCell::new(Some(f)) }
+	}
+}
+
+#[cfg(feature = "critical-section")]
+impl<T, F: FnOnce() -> T> LazyInitCell<T, F> {
+	/// Forces evaluation of this cell's value and returns a reference to it.
+	///
+	/// Unlike `Deref`, this reads as a plain associated function so it can't
+	/// be confused with the wrapped `T`'s own methods.
+	pub fn force(this: &Self) -> &T {
+		this.cell.get_or_init(|| {
+			let f = unsafe { (*this.init.get()).take() }
+				.expect("LazyInitCell initializer already taken");
+			f()
+		})
+	}
+}
+
+#[cfg(feature = "critical-section")]
+impl<T, F: FnOnce() -> T> Deref for LazyInitCell<T, F> {
+	type Target = T;
+
+	fn deref(&self) -> &T { Self::force(self) }
+}